@@ -0,0 +1,56 @@
+// Copyright 2023 Louis Royer. All rights reserved.
+// Use of this source code is governed by a MIT-style license that can be
+// found in the LICENSE file.
+// SPDX-License-Identifier: MIT
+
+use std::env;
+use std::process::ExitCode;
+
+use adventofcode_2023::days::day01::Day01;
+use adventofcode_2023::days::day02::Day02;
+use adventofcode_2023::input;
+use adventofcode_2023::solution::Solution;
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+    let Some(day) = args.next().and_then(|d| d.parse::<u8>().ok()) else {
+        eprintln!("Usage: aoc <day> [part]");
+        return ExitCode::FAILURE;
+    };
+    let part = args.next().and_then(|p| p.parse::<u8>().ok());
+
+    match day {
+        1 => run::<Day01>(part),
+        2 => run::<Day02>(part),
+        _ => {
+            eprintln!("Day {day} is not implemented");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Streams `D`'s input file and prints the requested part(s), or both when `part` is `None`.
+/// Each part re-reads the file through a buffered reader, so only one line is ever held in memory.
+fn run<D: Solution>(part: Option<u8>) -> ExitCode {
+    let input_path = D::input_path();
+
+    if part != Some(2) {
+        match input::lines(&input_path) {
+            Ok(lines) => match D::part_1(lines) {
+                Ok(answer) => println!("Part 1: {answer}"),
+                Err(_) => eprintln!("Could not compute part 1"),
+            },
+            Err(e) => eprintln!("Could not open input file {input_path}: {e}"),
+        }
+    }
+    if part != Some(1) {
+        match input::lines(&input_path) {
+            Ok(lines) => match D::part_2(lines) {
+                Ok(answer) => println!("Part 2: {answer}"),
+                Err(_) => eprintln!("Could not compute part 2"),
+            },
+            Err(e) => eprintln!("Could not open input file {input_path}: {e}"),
+        }
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,185 @@
+// Copyright 2023 Louis Royer. All rights reserved.
+// Use of this source code is governed by a MIT-style license that can be
+// found in the LICENSE file.
+// SPDX-License-Identifier: MIT
+
+use std::io;
+
+use nom::character::complete::one_of;
+use nom::combinator::map;
+use nom::IResult;
+
+use crate::parser::calibration_digit;
+use crate::problem::Problem;
+use crate::solution::Solution;
+
+#[derive(Debug, PartialEq)]
+pub struct ParseCalibrationError;
+
+/// Error produced while streaming and parsing the calibration document.
+#[derive(Debug, PartialEq)]
+pub enum CalibrationError {
+    Io(String),
+    Parse(ParseCalibrationError),
+}
+
+impl From<io::Error> for CalibrationError {
+    fn from(e: io::Error) -> Self {
+        CalibrationError::Io(e.to_string())
+    }
+}
+
+impl From<ParseCalibrationError> for CalibrationError {
+    fn from(e: ParseCalibrationError) -> Self {
+        CalibrationError::Parse(e)
+    }
+}
+
+/// Day 1: Trebuchet?!
+pub struct Day01;
+
+impl Problem for Day01 {
+    const DAY: u8 = 1;
+}
+
+impl Solution for Day01 {
+    type Err = CalibrationError;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn part_1(input: impl Iterator<Item = io::Result<String>>) -> Result<Self::Answer1, Self::Err> {
+        calibration_sum(input, false)
+    }
+
+    fn part_2(input: impl Iterator<Item = io::Result<String>>) -> Result<Self::Answer2, Self::Err> {
+        calibration_sum(input, true)
+    }
+}
+
+/// Returns the sum of calibration values, reading lines lazily so the whole
+/// document is never held in memory at once.
+/// When `spelled_digits` is set, digits spelled out with letters (`one`..`nine`) are recognized too.
+fn calibration_sum(
+    lines: impl Iterator<Item = io::Result<String>>,
+    spelled_digits: bool,
+) -> Result<u32, CalibrationError> {
+    let mut sum = 0;
+    for line in lines {
+        let line = line?; // surface I/O errors instead of panicking
+        if line.is_empty() {
+            continue; // exclude empty lines
+        }
+        sum += calibration(&line, spelled_digits)?;
+    }
+    Ok(sum)
+}
+
+/// Recognizes a single ascii digit (one to nine, zero doesn't count).
+fn ascii_digit(input: &str) -> IResult<&str, u32> {
+    map(one_of("123456789"), |c| c.to_digit(10).unwrap())(input)
+}
+
+/// The calibration value can be found by combining the first digit
+/// and the last digit (in that order) to form a single two-digit number.
+fn calibration(line: &str, spelled_digits: bool) -> Result<u32, ParseCalibrationError> {
+    let digit = if spelled_digits {
+        calibration_digit
+    } else {
+        ascii_digit
+    };
+
+    // Scan one character at a time, rather than skipping past each match, so that
+    // spelled-out digits sharing letters (e.g. "eightwo", "oneight") both count.
+    // Advance by char length rather than a fixed byte offset, since the line may
+    // contain multi-byte UTF-8 characters.
+    let mut digits = Vec::new();
+    let mut input = line;
+    while let Some(ch) = input.chars().next() {
+        if let Ok((_, value)) = digit(input) {
+            digits.push(value);
+        }
+        input = &input[ch.len_utf8()..];
+    }
+
+    // first digit
+    let first = *digits.first().ok_or(ParseCalibrationError)?;
+
+    // last digit
+    let last = *digits.last().ok_or(ParseCalibrationError)?;
+
+    // concatenate digits
+    Ok((first * 10) + last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    #[test]
+    fn it_calibration_sum() {
+        // part 1: only ascii digits
+        assert_eq!(
+            calibration_sum(
+                input::ok_lines(
+                    vec!["1abc2", "pqr3stu8vwx", "a1b2c3d4e5f", "treb7uchet"].into_iter()
+                ),
+                false,
+            ),
+            Ok(142)
+        );
+        // part 2: digits spelled with letters
+        assert_eq!(
+            calibration_sum(
+                input::ok_lines(
+                    vec![
+                        "two1nine",
+                        "eightwothree",
+                        "abcone2threexyz",
+                        "xtwone3four",
+                        "4nineeightseven2",
+                        "zoneight234",
+                        "7pqrstsixteen"
+                    ]
+                    .into_iter()
+                ),
+                true,
+            ),
+            Ok(281)
+        )
+    }
+
+    #[test]
+    fn it_calibration() {
+        assert!(calibration("abcd", false).is_err());
+        // part 1: only ascii digits
+        assert_eq!(calibration("1abc2", false), Ok(12));
+        assert_eq!(calibration("pqr3stu8vwx", false), Ok(38));
+        assert_eq!(calibration("a1b2c3d4e5f", false), Ok(15));
+        assert_eq!(calibration("treb7uchet", false), Ok(77));
+        // part 2: digits spelled with letters
+        assert_eq!(calibration("two1nine", true), Ok(29));
+        assert_eq!(calibration("eightwothree", true), Ok(83));
+        assert_eq!(calibration("abcone2threexyz", true), Ok(13));
+        assert_eq!(calibration("xtwone3four", true), Ok(24));
+        assert_eq!(calibration("4nineeightseven2", true), Ok(42));
+        assert_eq!(calibration("zoneight234", true), Ok(14));
+        assert_eq!(calibration("7pqrstsixteen", true), Ok(76));
+    }
+
+    #[test]
+    fn it_calibration_overlapping_words() {
+        // "eight" and "two" share the "t"; both must count
+        assert_eq!(calibration("eightwothree", true), Ok(83));
+        // "one" and "eight" share the "e"
+        assert_eq!(calibration("oneight", true), Ok(18));
+        // "seven" and "nine" share the "n"
+        assert_eq!(calibration("sevenine", true), Ok(79));
+    }
+
+    #[test]
+    fn it_calibration_multibyte_chars() {
+        // a multi-byte UTF-8 character must not split a byte out of its char boundary
+        assert_eq!(calibration("café1two2", true), Ok(12));
+    }
+}
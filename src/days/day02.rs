@@ -0,0 +1,366 @@
+// Copyright 2023 Louis Royer. All rights reserved.
+// Use of this source code is governed by a MIT-style license that can be
+// found in the LICENSE file.
+// SPDX-License-Identifier: MIT
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::io;
+use std::str::FromStr;
+
+use crate::parser;
+use crate::problem::Problem;
+use crate::solution::Solution;
+
+/// A game record failed to parse; `position` is the byte offset in the input
+/// where parsing broke down.
+#[derive(Debug, PartialEq)]
+pub struct ParseGameDataError {
+    pub position: usize,
+}
+
+/// Error produced while streaming and parsing the game records.
+#[derive(Debug, PartialEq)]
+pub enum GameError {
+    Io(String),
+    Parse(ParseGameDataError),
+}
+
+impl From<io::Error> for GameError {
+    fn from(e: io::Error) -> Self {
+        GameError::Io(e.to_string())
+    }
+}
+
+impl From<ParseGameDataError> for GameError {
+    fn from(e: ParseGameDataError) -> Self {
+        GameError::Parse(e)
+    }
+}
+
+/// A count of cubes of each color, either shown in a single draw or held by a bag.
+/// Colors are not limited to red/green/blue: any color name the input mentions is tracked.
+/// `a <= b` iff every color in `a` is `<=` the corresponding color in `b`, treating a color
+/// missing from one side as zero.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct CubeSet(HashMap<String, u32>);
+
+impl CubeSet {
+    fn new(counts: impl IntoIterator<Item = (&'static str, u32)>) -> Self {
+        CubeSet(
+            counts
+                .into_iter()
+                .map(|(color, count)| (color.to_string(), count))
+                .collect(),
+        )
+    }
+
+    fn from_draw(cubes: &[(u32, &str)]) -> Self {
+        let mut set = CubeSet::default();
+        for (count, color) in cubes {
+            set.0
+                .entry(color.to_string())
+                .and_modify(|c| *c = (*c).max(*count))
+                .or_insert(*count);
+        }
+        set
+    }
+
+    fn component_max(mut self, other: Self) -> Self {
+        for (color, count) in other.0 {
+            self.0
+                .entry(color)
+                .and_modify(|c| *c = (*c).max(count))
+                .or_insert(count);
+        }
+        self
+    }
+
+    /// Product of the cube counts across every color that appeared.
+    fn power(&self) -> u32 {
+        self.0.values().product()
+    }
+}
+
+impl PartialOrd for CubeSet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let (mut le, mut ge) = (true, true);
+        for color in self.0.keys().chain(other.0.keys()) {
+            let a = self.0.get(color).copied().unwrap_or(0);
+            let b = other.0.get(color).copied().unwrap_or(0);
+            le &= a <= b;
+            ge &= a >= b;
+        }
+        match (le, ge) {
+            (true, true) => Some(Ordering::Equal),
+            (true, false) => Some(Ordering::Less),
+            (false, true) => Some(Ordering::Greater),
+            (false, false) => None,
+        }
+    }
+}
+
+/// A game consists in taking several times a random number of cubes out of the bag.
+/// We keep every draw, rather than collapsing them to maximums while parsing, so the
+/// ordering check and the minimum required set can both be derived from the same data.
+#[derive(Debug, PartialEq)]
+struct Game {
+    id: u32,
+    draws: Vec<CubeSet>,
+}
+
+impl Game {
+    /// Smallest `CubeSet` that would have made every draw of this game possible:
+    /// the component-wise maximum of cube counts seen across all draws.
+    fn min_set(&self) -> CubeSet {
+        self.draws
+            .iter()
+            .cloned()
+            .fold(CubeSet::default(), CubeSet::component_max)
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseGameDataError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, (id, draws)) = parser::game(s).map_err(|e| ParseGameDataError {
+            position: match e {
+                nom::Err::Error(e) | nom::Err::Failure(e) => s.len() - e.input.len(),
+                nom::Err::Incomplete(_) => s.len(),
+            },
+        })?;
+
+        let draws = draws
+            .into_iter()
+            .map(|draw| CubeSet::from_draw(&draw))
+            .collect();
+
+        Ok(Game { id, draws })
+    }
+}
+
+/// Day 2: Cube Conundrum
+pub struct Day02;
+
+impl Problem for Day02 {
+    const DAY: u8 = 2;
+}
+
+impl Solution for Day02 {
+    type Err = GameError;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn part_1(input: impl Iterator<Item = io::Result<String>>) -> Result<Self::Answer1, Self::Err> {
+        let bag = CubeSet::new([("red", 12), ("green", 13), ("blue", 14)]);
+        game_id_sum(input, &bag)
+    }
+
+    fn part_2(input: impl Iterator<Item = io::Result<String>>) -> Result<Self::Answer2, Self::Err> {
+        power_sum(input)
+    }
+}
+
+/// Returns the sum of game ids which would have been possible with this game data,
+/// reading lines lazily so the whole document is never held in memory at once.
+fn game_id_sum(
+    lines: impl Iterator<Item = io::Result<String>>,
+    bag: &CubeSet,
+) -> Result<u32, GameError> {
+    let mut sum = 0;
+    for line in lines {
+        let line = line?; // surface I/O errors instead of panicking
+        if line.is_empty() {
+            continue; // exclude empty lines
+        }
+        let game = Game::from_str(&line)?;
+        if validate(&game, bag) {
+            // check validity of the game data according to bag content
+            sum += game.id;
+        }
+    }
+    Ok(sum)
+}
+
+/// A game is valid if the minimum set of cubes it requires fits in the bag.
+fn validate(data: &Game, bag: &CubeSet) -> bool {
+    data.min_set() <= *bag
+}
+
+fn power_sum(lines: impl Iterator<Item = io::Result<String>>) -> Result<u32, GameError> {
+    let mut sum = 0;
+    for line in lines {
+        let line = line?; // surface I/O errors instead of panicking
+        if line.is_empty() {
+            continue; // exclude empty lines
+        }
+        sum += Game::from_str(&line)?.min_set().power();
+    }
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    #[test]
+    fn it_game_id_sum() {
+        let bag = CubeSet::new([("red", 12), ("green", 13), ("blue", 14)]);
+        assert_eq!(
+            game_id_sum(
+                input::ok_lines(
+                    vec![
+                        "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+                        "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue",
+                        "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+                        "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red",
+                        "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
+                    ]
+                    .into_iter()
+                ),
+                &bag
+            ),
+            Ok(8)
+        );
+    }
+
+    #[test]
+    fn it_validate() {
+        let bag = CubeSet::new([("red", 12), ("green", 13), ("blue", 14)]);
+        assert!(validate(
+            &Game::from_str("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap(),
+            &bag
+        ));
+        assert!(validate(
+            &Game::from_str("Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue")
+                .unwrap(),
+            &bag
+        ));
+        assert!(!validate(
+            // too many red cubes in this game
+            &Game::from_str(
+                "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"
+            )
+            .unwrap(),
+            &bag
+        ));
+        assert!(!validate(
+            // too many blue cubes in this game
+            &Game::from_str(
+                "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red"
+            )
+            .unwrap(),
+            &bag
+        ));
+        assert!(validate(
+            &Game::from_str("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green").unwrap(),
+            &bag
+        ));
+    }
+
+    #[test]
+    fn it_game_min_set() {
+        assert_eq!(
+            Game::from_str("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green")
+                .unwrap()
+                .min_set(),
+            CubeSet::new([("red", 4), ("green", 2), ("blue", 6)])
+        );
+        assert_eq!(
+            Game::from_str("Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue")
+                .unwrap()
+                .min_set(),
+            CubeSet::new([("red", 1), ("green", 3), ("blue", 4)])
+        );
+        assert_eq!(
+            Game::from_str(
+                "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"
+            )
+            .unwrap()
+            .min_set(),
+            CubeSet::new([("red", 20), ("green", 13), ("blue", 6)])
+        );
+        assert_eq!(
+            Game::from_str(
+                "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red"
+            )
+            .unwrap()
+            .min_set(),
+            CubeSet::new([("red", 14), ("green", 3), ("blue", 15)])
+        );
+        assert_eq!(
+            Game::from_str("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green")
+                .unwrap()
+                .min_set(),
+            CubeSet::new([("red", 6), ("green", 3), ("blue", 2)])
+        );
+    }
+
+    #[test]
+    fn it_game_min_set_tracks_arbitrary_colors() {
+        assert_eq!(
+            Game::from_str("Game 1: 3 yellow, 4 red; 1 red, 2 yellow")
+                .unwrap()
+                .min_set(),
+            CubeSet::new([("red", 4), ("yellow", 3)])
+        );
+    }
+
+    #[test]
+    fn it_validate_rejects_unrecognized_color() {
+        // the standard red/green/blue bag has no "yellow" cubes, so any game
+        // drawing yellow is unsatisfiable no matter how few are drawn
+        let bag = CubeSet::new([("red", 12), ("green", 13), ("blue", 14)]);
+        assert!(!validate(&Game::from_str("Game 1: 1 yellow").unwrap(), &bag));
+    }
+
+    #[test]
+    fn it_parse_game_data_error_position() {
+        assert_eq!(
+            Game::from_str("Game 1 3 blue").unwrap_err(),
+            ParseGameDataError { position: 6 }
+        );
+    }
+
+    #[test]
+    fn it_cube_set_power() {
+        assert_eq!(
+            CubeSet::new([("red", 4), ("green", 2), ("blue", 6)]).power(),
+            48
+        );
+        assert_eq!(
+            CubeSet::new([("red", 1), ("green", 3), ("blue", 4)]).power(),
+            12
+        );
+        assert_eq!(
+            CubeSet::new([("red", 20), ("green", 13), ("blue", 6)]).power(),
+            1560
+        );
+        assert_eq!(
+            CubeSet::new([("red", 14), ("green", 3), ("blue", 15)]).power(),
+            630
+        );
+        assert_eq!(
+            CubeSet::new([("red", 6), ("green", 3), ("blue", 2)]).power(),
+            36
+        );
+    }
+
+    #[test]
+    fn it_power_sum() {
+        assert_eq!(
+            power_sum(input::ok_lines(
+                vec![
+                    "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+                    "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue",
+                    "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+                    "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red",
+                    "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
+                ]
+                .into_iter()
+            )),
+            Ok(2286)
+        )
+    }
+}
@@ -0,0 +1,23 @@
+// Copyright 2023 Louis Royer. All rights reserved.
+// Use of this source code is governed by a MIT-style license that can be
+// found in the LICENSE file.
+// SPDX-License-Identifier: MIT
+
+//! Streaming line input: wraps a buffered reader so puzzle input is processed
+//! one line at a time instead of being materialized into a single `String`.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+
+/// Opens `path` and lazily yields its lines, one line at a time, through a buffered reader.
+pub fn lines(path: &str) -> io::Result<impl Iterator<Item = io::Result<String>>> {
+    Ok(BufReader::new(File::open(path)?).lines())
+}
+
+/// Adapts an iterator of borrowed lines, such as the literals used in tests, into the
+/// same `io::Result<String>` shape produced by [`lines`].
+pub fn ok_lines<'a>(
+    lines: impl Iterator<Item = &'a str> + 'a,
+) -> impl Iterator<Item = io::Result<String>> + 'a {
+    lines.map(|line| Ok(line.to_string()))
+}
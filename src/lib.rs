@@ -0,0 +1,10 @@
+// Copyright 2023 Louis Royer. All rights reserved.
+// Use of this source code is governed by a MIT-style license that can be
+// found in the LICENSE file.
+// SPDX-License-Identifier: MIT
+
+pub mod days;
+pub mod input;
+pub mod parser;
+pub mod problem;
+pub mod solution;
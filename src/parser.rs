@@ -0,0 +1,58 @@
+// Copyright 2023 Louis Royer. All rights reserved.
+// Use of this source code is governed by a MIT-style license that can be
+// found in the LICENSE file.
+// SPDX-License-Identifier: MIT
+
+//! Shared `nom` parser combinators, used in place of per-line `Regex` compilation.
+
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, one_of, u32 as nom_u32};
+use nom::combinator::{map, value};
+use nom::multi::separated_list1;
+use nom::sequence::{preceded, separated_pair};
+use nom::IResult;
+
+/// Recognizes a single calibration digit (`1`..`9`) or a spelled-out word (`one`..`nine`)
+/// at the start of the input, returning its numeric value.
+pub fn calibration_digit(input: &str) -> IResult<&str, u32> {
+    alt((
+        map(one_of("123456789"), |c| c.to_digit(10).unwrap()),
+        value(1, tag("one")),
+        value(2, tag("two")),
+        value(3, tag("three")),
+        value(4, tag("four")),
+        value(5, tag("five")),
+        value(6, tag("six")),
+        value(7, tag("seven")),
+        value(8, tag("eight")),
+        value(9, tag("nine")),
+    ))(input)
+}
+
+/// Recognizes a cube color, named by any alphabetic word (not just `red`/`green`/`blue`).
+fn cube_color(input: &str) -> IResult<&str, &str> {
+    alpha1(input)
+}
+
+/// Recognizes a single `<count> <color>` cube draw, e.g. `3 blue`.
+fn cube(input: &str) -> IResult<&str, (u32, &str)> {
+    separated_pair(nom_u32, tag(" "), cube_color)(input)
+}
+
+/// Recognizes a comma-separated handful of cubes drawn at once, e.g. `3 blue, 4 red`.
+fn draw(input: &str) -> IResult<&str, Vec<(u32, &str)>> {
+    separated_list1(tag(", "), cube)(input)
+}
+
+/// A game's id paired with the semicolon-delimited sequence of draws that were made.
+type GameRecord<'a> = (u32, Vec<Vec<(u32, &'a str)>>);
+
+/// Recognizes a full game record, e.g. `Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue`,
+/// returning its id and the semicolon-delimited sequence of draws.
+pub fn game(input: &str) -> IResult<&str, GameRecord<'_>> {
+    let (input, id) = preceded(tag("Game "), nom_u32)(input)?;
+    let (input, _) = tag(": ")(input)?;
+    let (input, draws) = separated_list1(tag("; "), draw)(input)?;
+    Ok((input, (id, draws)))
+}
@@ -0,0 +1,15 @@
+// Copyright 2023 Louis Royer. All rights reserved.
+// Use of this source code is governed by a MIT-style license that can be
+// found in the LICENSE file.
+// SPDX-License-Identifier: MIT
+
+/// Identifies an Advent of Code puzzle by its day number.
+pub trait Problem {
+    /// Day number (1-25), used to locate this day's input file.
+    const DAY: u8;
+
+    /// Path to this day's input file, e.g. `inputs/01.in`.
+    fn input_path() -> String {
+        format!("inputs/{:02}.in", Self::DAY)
+    }
+}
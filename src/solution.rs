@@ -0,0 +1,22 @@
+// Copyright 2023 Louis Royer. All rights reserved.
+// Use of this source code is governed by a MIT-style license that can be
+// found in the LICENSE file.
+// SPDX-License-Identifier: MIT
+
+use std::fmt::Display;
+use std::io;
+
+use crate::problem::Problem;
+
+/// A puzzle solution, split into its two parts.
+pub trait Solution: Problem {
+    /// Error returned when the input cannot be read or parsed.
+    type Err;
+    /// Answer produced by part 1.
+    type Answer1: Display;
+    /// Answer produced by part 2.
+    type Answer2: Display;
+
+    fn part_1(input: impl Iterator<Item = io::Result<String>>) -> Result<Self::Answer1, Self::Err>;
+    fn part_2(input: impl Iterator<Item = io::Result<String>>) -> Result<Self::Answer2, Self::Err>;
+}